@@ -579,7 +579,7 @@ namespace iroha {
       auto cmd =
           fmt::format(R"(WITH {},
       t AS (
-          SELECT a.account_id, a.domain_id, a.quorum, a.data, ARRAY_AGG(ar.role_id) AS roles
+          SELECT a.account_id, a.domain_id, a.quorum, a.data, ARRAY_AGG(ar.role_id ORDER BY ar.role_id) AS roles
           FROM account AS a, account_has_roles AS ar
           WHERE a.account_id = :target_account_id
           AND ar.account_id = a.account_id
@@ -686,6 +686,7 @@ namespace iroha {
       )
       SELECT public_key, perm FROM t
       RIGHT OUTER JOIN has_perms ON TRUE
+      ORDER BY public_key
       )",
                       hasQueryPermissionTarget(creator_id,
                                                q.accountId(),
@@ -1285,6 +1286,7 @@ namespace iroha {
           R"(WITH has_perms AS ({})
       SELECT role_id, perm FROM role
       RIGHT OUTER JOIN has_perms ON TRUE
+      ORDER BY role_id
       )",
           getAccountRolePermissionCheckSql(Role::kGetRoles));
 
@@ -1484,6 +1486,7 @@ namespace iroha {
       UNION
       SELECT public_key, address, tls_certificate, perm FROM sync_peer
       RIGHT OUTER JOIN has_perms ON TRUE
+      ORDER BY public_key
       )",
           getAccountRolePermissionCheckSql(Role::kGetPeers));
 
@@ -1546,7 +1549,7 @@ namespace iroha {
               left join burrow_tx_logs on engine_calls.call_id = burrow_tx_logs.call_id
               left join burrow_tx_logs_topics on burrow_tx_logs.log_idx = burrow_tx_logs_topics.log_idx
               right outer join has_perms on true
-            order by engine_calls.cmd_index asc
+            order by engine_calls.cmd_index asc, burrow_tx_logs.log_idx asc
             )",
           hasQueryPermissionInternal(creator_id,
                                      Role::kGetMyEngineReceipts,
@@ -33,6 +33,7 @@
 #include "consensus/yac/supermajority_checker.hpp"
 #include "cryptography/crypto_provider/crypto_model_signer.hpp"
 #include "cryptography/default_hash_provider.hpp"
+#include "datetime/time.hpp"
 #include "generator/generator.hpp"
 #include "interfaces/common_objects/string_view_types.hpp"
 #include "interfaces/iroha_internal/transaction_batch_factory_impl.hpp"
@@ -44,6 +45,7 @@
 #include "main/impl/pg_connection_init.hpp"
 #include "main/impl/rocksdb_connection_init.hpp"
 #include "main/impl/storage_init.hpp"
+#include "main/impl/time_response.hpp"
 #include "main/iroha_status.hpp"
 #include "main/server_runner.hpp"
 #include "main/subscription.hpp"
@@ -279,13 +281,25 @@ Irohad::RunResult Irohad::initSettings() {
 Irohad::RunResult Irohad::initValidatorsConfigs() {
   validators_config_ =
       std::make_shared<shared_model::validation::ValidatorsConfig>(
-          config_.max_proposal_size, false, false, config_.max_past_created_hours);
+          config_.max_proposal_size,
+          false,
+          false,
+          config_.max_past_created_hours,
+          config_.max_future_created_seconds);
   block_validators_config_ =
       std::make_shared<shared_model::validation::ValidatorsConfig>(
-          config_.max_proposal_size, true, false, config_.max_past_created_hours);
+          config_.max_proposal_size,
+          true,
+          false,
+          config_.max_past_created_hours,
+          config_.max_future_created_seconds);
   proposal_validators_config_ =
       std::make_shared<shared_model::validation::ValidatorsConfig>(
-          config_.max_proposal_size, false, true, config_.max_past_created_hours);
+          config_.max_proposal_size,
+          false,
+          true,
+          config_.max_past_created_hours,
+          config_.max_future_created_seconds);
   log_->info("[Init] => validators configs");
   return {};
 }
@@ -305,7 +319,8 @@ Irohad::RunResult Irohad::initHttpServer() {
 
   http_server_->registerHandler(
       "/healthcheck",
-      [status_sub(iroha_status_subscription_)](
+      [status_sub(iroha_status_subscription_),
+       syncing_node(config_.syncing_mode)](
           iroha::network::HttpRequestResponse &req_res) {
         status_sub->get().exclusiveAccess(
             [&](iroha::IrohaStoredStatus &status) {
@@ -352,6 +367,9 @@ Irohad::RunResult Irohad::initHttpServer() {
                            status.status.is_syncing.has_value(),
                            *status.status.is_syncing);
 
+                writer.Key("syncing_node");
+                writer.Bool(syncing_node);
+
                 writer.Key("status");
                 setOptBool(writer,
                            status.status.is_healthy.has_value(),
@@ -364,6 +382,11 @@ Irohad::RunResult Irohad::initHttpServer() {
                                    status.serialized_status.GetLength()));
             });
       });
+
+  http_server_->registerHandler(
+      "/time", [](iroha::network::HttpRequestResponse &req_res) {
+        req_res.setJsonResponse(iroha::makeTimeResponse(iroha::time::now()));
+      });
   return {};
 }
 
@@ -743,6 +766,7 @@ Irohad::RunResult Irohad::initOrderingGate() {
 
   ordering_gate = ordering_init->initOrderingGate(
       config_.max_proposal_size,
+      config_.max_proposal_bytes,
       config_.getMaxpProposalPack(),
       std::chrono::milliseconds(config_.getProposalDelay()),
       transaction_factory,
@@ -988,7 +1012,8 @@ Irohad::RunResult Irohad::initTransactionCommandService() {
       status_factory,
       cs_cache,
       persistent_cache,
-      command_service_log_manager->getLogger());
+      command_service_log_manager->getLogger(),
+      config_.max_proposal_bytes);
   command_service_transport =
       std::make_shared<::torii::CommandServiceTransportGrpc>(
           command_service,
@@ -1129,17 +1154,34 @@ Irohad::RunResult Irohad::run() {
     }
   });
 
+  std::optional<std::chrono::milliseconds> torii_keepalive_time;
+  if (config_.torii_keepalive_time) {
+    torii_keepalive_time =
+        std::chrono::milliseconds(*config_.torii_keepalive_time);
+  }
+  std::optional<std::chrono::milliseconds> torii_keepalive_timeout;
+  if (config_.torii_keepalive_timeout) {
+    torii_keepalive_timeout =
+        std::chrono::milliseconds(*config_.torii_keepalive_timeout);
+  }
+
   // Initializing torii server
   torii_server = std::make_unique<ServerRunner>(
       listen_ip_ + ":" + std::to_string(config_.torii_port),
       log_manager_->getChild("ToriiServerRunner")->getLogger(),
-      false);
+      false,
+      boost::none,
+      config_.torii_compression,
+      torii_keepalive_time,
+      torii_keepalive_timeout);
 
   // Initializing internal server
   internal_server = std::make_unique<ServerRunner>(
       listen_ip_ + ":" + std::to_string(config_.internal_port),
       log_manager_->getChild("InternalServerRunner")->getLogger(),
-      false);
+      false,
+      boost::none,
+      config_.internal_compression);
 
   // Run torii server
   IROHA_EXPECTED_TRY_GET_VALUE(torii_port,
@@ -1154,7 +1196,10 @@ Irohad::RunResult Irohad::run() {
         listen_ip_ + ":" + std::to_string(config_.torii_tls_params->port),
         log_manager_->getChild("ToriiTlsServerRunner")->getLogger(),
         false,
-        *torii_tls_creds_);
+        *torii_tls_creds_,
+        config_.torii_compression,
+        torii_keepalive_time,
+        torii_keepalive_timeout);
     IROHA_EXPECTED_TRY_GET_VALUE(torii_tls_port,
                                  torii_tls_server.value()
                                      ->append(command_service_transport)
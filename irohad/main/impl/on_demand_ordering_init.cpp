@@ -97,6 +97,7 @@ auto OnDemandOrderingInit::createGate(
 
 auto OnDemandOrderingInit::createService(
     size_t max_number_of_transactions,
+    std::optional<size_t> max_proposal_bytes,
     uint32_t max_proposal_pack,
     std::shared_ptr<shared_model::interface::UnsafeProposalFactory>
         proposal_factory,
@@ -107,13 +108,15 @@ auto OnDemandOrderingInit::createService(
       max_proposal_pack,
       std::move(proposal_factory),
       std::move(tx_cache),
-      ordering_log_manager->getChild("Service")->getLogger());
+      ordering_log_manager->getChild("Service")->getLogger(),
+      max_proposal_bytes);
   return ordering_service_;
 }
 
 std::shared_ptr<iroha::network::OrderingGate>
 OnDemandOrderingInit::initOrderingGate(
     size_t max_number_of_transactions,
+    std::optional<size_t> max_proposal_bytes,
     uint32_t max_proposal_pack,
     std::chrono::milliseconds delay,
     std::shared_ptr<transport::OnDemandOsServerGrpc::TransportFactoryType>
@@ -133,6 +136,7 @@ OnDemandOrderingInit::initOrderingGate(
   std::shared_ptr<OnDemandOrderingService> ordering_service;
   if (!syncing_mode) {
     ordering_service = createService(max_number_of_transactions,
+                                     max_proposal_bytes,
                                      max_proposal_pack,
                                      proposal_factory,
                                      tx_cache,
@@ -7,6 +7,7 @@
 #define IROHA_ON_DEMAND_ORDERING_INIT_HPP
 
 #include <chrono>
+#include <optional>
 #include <vector>
 
 #include "cryptography/hash.hpp"
@@ -104,6 +105,7 @@ namespace iroha::ordering {
      */
     auto createService(
         size_t max_number_of_transactions,
+        std::optional<size_t> max_proposal_bytes,
         uint32_t max_proposal_pack,
         std::shared_ptr<shared_model::interface::UnsafeProposalFactory>
             proposal_factory,
@@ -120,6 +122,8 @@ namespace iroha::ordering {
      *
      * @param max_number_of_transactions maximum number of transactions in a
      * proposal
+     * @param max_proposal_bytes maximum size of transactions in a proposal in
+     * bytes, unlimited if not set
      * @param delay timeout for ordering service response on proposal request
      * @param transaction_factory transport factory for transactions required
      * by ordering service network endpoint
@@ -134,6 +138,7 @@ namespace iroha::ordering {
      */
     std::shared_ptr<network::OrderingGate> initOrderingGate(
         size_t max_number_of_transactions,
+        std::optional<size_t> max_proposal_bytes,
         uint32_t max_proposal_pack,
         std::chrono::milliseconds delay,
         std::shared_ptr<shared_model::interface::AbstractTransportFactory<
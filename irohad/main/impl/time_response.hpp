@@ -0,0 +1,24 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_TIME_RESPONSE_HPP
+#define IROHA_TIME_RESPONSE_HPP
+
+#include <string>
+
+#include "datetime/time.hpp"
+
+namespace iroha {
+
+  /**
+   * Make the body of `/time` HTTP endpoint response.
+   * @param now - peer's current time in milliseconds since epoch
+   * @return JSON object `{"time":<now>}`
+   */
+  std::string makeTimeResponse(ts64_t now);
+
+}  // namespace iroha
+
+#endif  // IROHA_TIME_RESPONSE_HPP
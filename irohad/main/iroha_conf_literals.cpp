@@ -9,6 +9,9 @@ namespace config_members {
   const char *BlockStorePath = "block_store_path";
   const char *ToriiPort = "torii_port";
   const char *ToriiTlsParams = "torii_tls_params";
+  const char *ToriiCompression = "torii_compression";
+  const char *ToriiKeepaliveTime = "torii_keepalive_time";
+  const char *ToriiKeepaliveTimeout = "torii_keepalive_timeout";
   const char *InterPeerTls = "inter_peer_tls";
   const char *PeerCertProvider = "peer_certificates";
   const char *RootCert = "root_certificate";
@@ -16,6 +19,7 @@ namespace config_members {
   const char *Type = "type";
   const char *Path = "path";
   const char *InternalPort = "internal_port";
+  const char *InternalCompression = "internal_compression";
   const char *KeyPairPath = "key_pair_path";
   const char *PgOpt = "pg_opt";
   const char *DbConfig = "database";
@@ -29,6 +33,7 @@ namespace config_members {
   const char *DbPath = "path";
   const char *DbType = "type";
   const char *MaxProposalSize = "max_proposal_size";
+  const char *MaxProposalBytes = "max_proposal_bytes";
   const char *ProposalCreationTimeout = "proposal_creation_timeout";
   const char *HealthcheckPort = "healthcheck_port";
   const char *MaxProposalPack = "max_proposal_pack";
@@ -49,6 +54,7 @@ namespace config_members {
       {"error", logger::LogLevel::kError},
       {"critical", logger::LogLevel::kCritical}};
   const char *MaxPastCreatedHours = "max_past_created_hours";
+  const char *MaxFutureCreatedSeconds = "max_future_created_seconds";
   const char *Address = "address";
   const char *PublicKey = "public_key";
   const char *InitialPeers = "initial_peers";
@@ -15,6 +15,9 @@ namespace config_members {
   extern const char *BlockStorePath;
   extern const char *ToriiPort;
   extern const char *ToriiTlsParams;
+  extern const char *ToriiCompression;
+  extern const char *ToriiKeepaliveTime;
+  extern const char *ToriiKeepaliveTimeout;
   extern const char *InterPeerTls;
   extern const char *PeerCertProvider;
   extern const char *RootCert;
@@ -22,6 +25,7 @@ namespace config_members {
   extern const char *Type;
   extern const char *Path;
   extern const char *InternalPort;
+  extern const char *InternalCompression;
   extern const char *KeyPairPath;
   extern const char *PgOpt;
   extern const char *DbConfig;
@@ -35,6 +39,7 @@ namespace config_members {
   extern const char *DbPath;
   extern const char *DbType;
   extern const char *MaxProposalSize;
+  extern const char *MaxProposalBytes;
   extern const char *ProposalCreationTimeout;
   extern const char *HealthcheckPort;
   extern const char *MaxProposalPack;
@@ -48,6 +53,7 @@ namespace config_members {
   extern const char *LogPatternsSection;
   extern const char *LogChildrenSection;
   extern const char *MaxPastCreatedHours;
+  extern const char *MaxFutureCreatedSeconds;
   extern const std::unordered_map<std::string, logger::LogLevel> LogLevels;
   extern const char *InitialPeers;
   extern const char *Address;
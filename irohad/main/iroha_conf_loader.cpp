@@ -5,6 +5,7 @@
 
 #include "main/iroha_conf_loader.hpp"
 
+#include <algorithm>
 #include <cctype>
 #include <cstddef>
 #include <cstdlib>
@@ -25,12 +26,15 @@
 #include <rapidjson/error/en.h>
 #include <rapidjson/rapidjson.h>
 #include <boost/algorithm/string/join.hpp>
+#include <boost/algorithm/string/predicate.hpp>
 #include <boost/range/adaptor/map.hpp>
 #include "common/bind.hpp"
 #include "common/files.hpp"
+#include "common/hexutils.hpp"
 #include "common/result.hpp"
 #include "logger/logger.hpp"
 #include "main/iroha_conf_literals.hpp"
+#include "main/server_runner.hpp"
 #include "torii/tls_params.hpp"
 
 /// The length of the string around the error place to print in case of JSON
@@ -241,6 +245,20 @@ class JsonDeserializerImpl {
     ::assert_fatal(condition, printable_path_, error);
   }
 
+  /**
+   * Load the name of a gRPC compression algorithm and check that grpc knows
+   * it.
+   */
+  bool loadCompressionAlgorithmInto(std::optional<std::string> &dest);
+
+  /**
+   * Load an optional unsigned integer and check that it lies within
+   * [min, max].
+   */
+  bool loadBoundedInto(std::optional<uint32_t> &dest,
+                       uint32_t min,
+                       uint32_t max = std::numeric_limits<uint32_t>::max());
+
   // ------------ loadInto(path, dst, src) ------------
   // loadInto is a set of functions that load the value from rapidjson::Value to
   // a given destination variable. They check the JSON type and throw exception
@@ -678,6 +696,29 @@ inline bool JsonDeserializerImpl::loadInto(IrohadConfig::Crypto &dest) {
       and getDictChild(config_members::kSigner).loadInto(dest.signer);
 }
 
+inline bool JsonDeserializerImpl::loadCompressionAlgorithmInto(
+    std::optional<std::string> &dest) {
+  if (not loadInto(dest)) {
+    return false;
+  }
+  if (dest) {
+    assert_fatal(iroha::network::parseCompressionAlgorithm(*dest).has_value(),
+                 fmt::format("unknown compression algorithm `{}'",
+                             dest.value()));
+  }
+  return true;
+}
+
+inline bool JsonDeserializerImpl::loadBoundedInto(
+    std::optional<uint32_t> &dest, uint32_t min, uint32_t max) {
+  if (not loadInto(dest)) {
+    return false;
+  }
+  assert_fatal(not dest or (*dest >= min and *dest <= max),
+               fmt::format("must be in range [{}, {}]", min, max));
+  return true;
+}
+
 uint32_t IrohadConfig::getMaxpProposalPack() const {
   return max_proposal_pack.value_or(10);
 }
@@ -688,16 +729,32 @@ inline bool JsonDeserializerImpl::loadInto(IrohadConfig &dest) {
   return getDictChild(BlockStorePath).loadInto(dest.block_store_path)
       and getDictChild(ToriiPort).loadInto(dest.torii_port)
       and getDictChild(ToriiTlsParams).loadInto(dest.torii_tls_params)
+      and getDictChild(ToriiCompression)
+              .loadCompressionAlgorithmInto(dest.torii_compression)
+      and getDictChild(ToriiKeepaliveTime)
+              .loadBoundedInto(dest.torii_keepalive_time,
+                               1,
+                               std::numeric_limits<int>::max())
+      and getDictChild(ToriiKeepaliveTimeout)
+              .loadBoundedInto(dest.torii_keepalive_timeout,
+                               1,
+                               std::numeric_limits<int>::max())
       and getDictChild(InterPeerTls).loadInto(dest.inter_peer_tls)
       and getDictChild(InternalPort).loadInto(dest.internal_port)
+      and getDictChild(InternalCompression)
+              .loadCompressionAlgorithmInto(dest.internal_compression)
       and getDictChild(DbConfig).loadInto(dest.database_config)
       and (dest.database_config or getDictChild(PgOpt).loadInto(dest.pg_opt))
       and getDictChild(MaxProposalSize).loadInto(dest.max_proposal_size)
+      and getDictChild(MaxProposalBytes)
+              .loadBoundedInto(dest.max_proposal_bytes, 1)
       and getDictChild(ProposalCreationTimeout)
               .loadInto(dest.proposal_creation_timeout)
       and getDictChild(MaxProposalPack).loadInto(dest.max_proposal_pack)
       and getDictChild(HealthcheckPort).loadInto(dest.healthcheck_port)
       and getDictChild(MaxPastCreatedHours).loadInto(dest.max_past_created_hours)
+      and getDictChild(MaxFutureCreatedSeconds)
+              .loadInto(dest.max_future_created_seconds)
       and getDictChild(VoteDelay).loadInto(dest.vote_delay)
       and getDictChild(MstSupport).loadInto(dest.mst_support)
       and getDictChild(MstExpirationTime).loadInto(dest.mst_expiration_time)
@@ -790,6 +847,107 @@ iroha::expected::Result<IrohadConfig, std::string> parse_iroha_config(
   };
 }
 
+std::vector<IrohadConfigIssue> checkCryptoConfig(
+    IrohadConfig::Crypto const &crypto) {
+  using namespace config_members;
+  using Severity = IrohadConfigIssue::Severity;
+  std::vector<IrohadConfigIssue> issues;
+
+  auto const provider_it = crypto.providers.find(crypto.signer);
+  if (provider_it == crypto.providers.end()) {
+    issues.push_back(
+        {Severity::kError,
+         fmt::format("{}/{}", kCrypto, kSigner),
+         fmt::format("crypto provider `{}' is not specified", crypto.signer)});
+    return issues;
+  }
+
+  auto const private_key_field = fmt::format(
+      "{}/{}/{}/{}", kCrypto, kProviders, crypto.signer, PrivateKey);
+  auto const &private_key = provider_it->second.private_key;
+  if (not private_key) {
+    issues.push_back({Severity::kError,
+                      private_key_field,
+                      "missing, the signer needs a private key"});
+  } else if (auto error = iroha::expected::resultToOptionalError(
+                 iroha::hexstringToBytestringResult(*private_key))) {
+    issues.push_back({Severity::kError,
+                      private_key_field,
+                      fmt::format("malformed hex string: {}", *error)});
+  }
+
+  return issues;
+}
+
+std::vector<IrohadConfigIssue> checkIrohadConfig(
+    IrohadConfig const &config,
+    std::string_view keypair_name,
+    std::optional<std::string_view> own_public_key) {
+  using namespace config_members;
+  using Severity = IrohadConfigIssue::Severity;
+  std::vector<IrohadConfigIssue> issues;
+
+  if (not config.database_config) {
+    if (config.pg_opt) {
+      issues.push_back(
+          {Severity::kWarning,
+           PgOpt,
+           fmt::format("deprecated, use `{}' section instead", DbConfig)});
+    } else {
+      issues.push_back(
+          {Severity::kError, DbConfig, "missing database configuration"});
+    }
+  }
+
+  if (config.torii_port == config.internal_port) {
+    issues.push_back({Severity::kError,
+                      InternalPort,
+                      fmt::format("port {} is already used by `{}'",
+                                  config.internal_port,
+                                  ToriiPort)});
+  }
+
+  if (keypair_name.empty() and not config.crypto) {
+    issues.push_back({Severity::kError,
+                      kCrypto,
+                      "no keypair specified, either set this section or pass "
+                      "--keypair_name"});
+  }
+
+  if (keypair_name.empty() and config.crypto) {
+    auto crypto_issues = checkCryptoConfig(*config.crypto);
+    issues.insert(issues.end(),
+                  std::make_move_iterator(crypto_issues.begin()),
+                  std::make_move_iterator(crypto_issues.end()));
+  }
+
+  if (config.initial_peers) {
+    if (config.initial_peers->empty()) {
+      issues.push_back({Severity::kError,
+                        InitialPeers,
+                        "empty list, either specify some peers or remove the "
+                        "field to use the peers from genesis block"});
+    } else if (own_public_key) {
+      bool const is_own_peer_listed = std::any_of(
+          config.initial_peers->begin(),
+          config.initial_peers->end(),
+          [&own_public_key](auto const &peer) {
+            return boost::iequals(peer->pubkey(), *own_public_key);
+          });
+      if (not is_own_peer_listed) {
+        issues.push_back(
+            {Severity::kWarning,
+             InitialPeers,
+             fmt::format("own public key {} is not in the list, the peer "
+                         "will not take part in consensus",
+                         *own_public_key)});
+      }
+    }
+  }
+
+  return issues;
+}
+
 uint32_t IrohadConfig::getProposalDelay() const {
   return getProposalCreationTimeout() * 2ul;
 }
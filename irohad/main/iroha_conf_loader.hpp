@@ -6,8 +6,11 @@
 #ifndef IROHA_CONF_LOADER_HPP
 #define IROHA_CONF_LOADER_HPP
 
+#include <optional>
 #include <string>
+#include <string_view>
 #include <unordered_map>
+#include <vector>
 
 #include "common/result_fwd.hpp"
 #include "interfaces/common_objects/common_objects_factory.hpp"
@@ -54,13 +57,18 @@ struct IrohadConfig {
   boost::optional<std::string> block_store_path;
   uint16_t torii_port;
   boost::optional<iroha::torii::TlsParams> torii_tls_params;
+  std::optional<std::string> torii_compression;
+  std::optional<uint32_t> torii_keepalive_time;
+  std::optional<uint32_t> torii_keepalive_timeout;
   boost::optional<InterPeerTls> inter_peer_tls;
   uint16_t internal_port;
+  std::optional<std::string> internal_compression;
   boost::optional<std::string>
       pg_opt;  // TODO 2019.06.26 mboldyrev IR-556 remove
   boost::optional<DbConfig>
       database_config;  // TODO 2019.06.26 mboldyrev IR-556 make required
   uint32_t max_proposal_size;
+  std::optional<uint32_t> max_proposal_bytes;
   uint32_t vote_delay;
   [[deprecated]] bool mst_support;
   bool syncing_mode;
@@ -74,6 +82,7 @@ struct IrohadConfig {
   std::optional<shared_model::interface::types::PeerList> initial_peers;
   boost::optional<UtilityService> utility_service;
   std::optional<uint32_t> max_past_created_hours;
+  std::optional<uint32_t> max_future_created_seconds;
   // getters
   uint32_t getMaxpProposalPack() const;
   uint32_t getProposalDelay() const;
@@ -114,4 +123,38 @@ iroha::expected::Result<IrohadConfig, std::string> parse_iroha_config(
         common_objects_factory,
     std::optional<logger::LoggerPtr> log);
 
+/**
+ * A problem found in a parsed configuration.
+ */
+struct IrohadConfigIssue {
+  enum class Severity { kError, kWarning };
+
+  Severity severity;
+  /// path of the configuration field the problem refers to
+  std::string field;
+  std::string message;
+};
+
+/**
+ * Check that a keypair can be created from the crypto section.
+ * @param crypto - the parsed crypto section
+ * @return all problems found, empty if none
+ */
+std::vector<IrohadConfigIssue> checkCryptoConfig(
+    IrohadConfig::Crypto const &crypto);
+
+/**
+ * Check the parts of a parsed configuration that are otherwise only verified
+ * late during startup.
+ * @param config - the parsed configuration
+ * @param keypair_name - keypair file name passed with --keypair_name, empty if
+ * not passed
+ * @param own_public_key - public key of the peer if the keypair was loaded
+ * @return all problems found, empty if none
+ */
+std::vector<IrohadConfigIssue> checkIrohadConfig(
+    IrohadConfig const &config,
+    std::string_view keypair_name,
+    std::optional<std::string_view> own_public_key);
+
 #endif  // IROHA_CONF_LOADER_HPP
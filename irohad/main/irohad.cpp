@@ -121,6 +121,14 @@ DEFINE_bool(exit_after_init, false, "Use this flag to reindex WSV and exit");
 
 DEFINE_bool(syncing_node, false, "Use this flag to run iroha as syncing node");
 
+/**
+ * Startup option to check the configuration and exit.
+ */
+DEFINE_bool(validate_config,
+            false,
+            "Check the configuration file and keypair, report the problems "
+            "found and exit. Parsing stops at the first malformed field");
+
 std::sig_atomic_t caught_signal = 0;
 std::promise<void> exit_requested;
 
@@ -140,9 +148,20 @@ static shared_model::crypto::Keypair getKeypairFromConfig(
   }
   auto const &signer = provider_it->second;
 
+  if (not signer.private_key) {
+    throw std::runtime_error{
+        fmt::format("crypto provider `{}' has no private key", config.signer)};
+  }
+  auto private_key_bytes =
+      iroha::hexstringToBytestringResult(*signer.private_key);
+  if (auto error = iroha::expected::resultToOptionalError(private_key_bytes)) {
+    throw std::runtime_error{
+        fmt::format("malformed private key of crypto provider `{}': {}",
+                    config.signer,
+                    *error)};
+  }
   shared_model::crypto::PrivateKey private_key{
-      iroha::hexstringToBytestringResult(signer.private_key.value())
-          .assumeValue()};
+      std::move(private_key_bytes).assumeValue()};
 
   switch (signer.type) {
     case iroha::multihash::Type::ed25519_sha3_256:
@@ -198,6 +217,54 @@ void initUtilityService(
   daemon_status_notifier = utility_service;
 }
 
+/**
+ * Loads the keypair and checks the configuration, logging every problem found
+ * together with the configuration field it refers to.
+ * @param config - the parsed configuration
+ * @param log_manager - log manager used to load the keypair
+ * @param log - logger to report the problems to
+ * @return true if no errors were found, false otherwise
+ */
+static bool checkConfig(IrohadConfig const &config,
+                        logger::LoggerManagerTreePtr log_manager,
+                        logger::LoggerPtr const &log) {
+  bool is_valid = true;
+
+  boost::optional<shared_model::crypto::Keypair> keypair = boost::none;
+  try {
+    if (!FLAGS_keypair_name.empty()) {
+      keypair = getKeypairFromFile(FLAGS_keypair_name, log_manager);
+    } else if (config.crypto.has_value()
+               and checkCryptoConfig(config.crypto.value()).empty()) {
+      // otherwise the problems are reported by checkIrohadConfig below
+      keypair = getKeypairFromConfig(config.crypto.value());
+    }
+  } catch (std::exception const &e) {
+    log->error("{}: {}",
+               FLAGS_keypair_name.empty() ? config_members::kCrypto
+                                          : "--keypair_name",
+               e.what());
+    is_valid = false;
+  }
+
+  std::optional<std::string_view> own_public_key;
+  if (keypair) {
+    own_public_key = keypair->publicKey();
+  }
+
+  for (auto const &issue :
+       checkIrohadConfig(config, FLAGS_keypair_name, own_public_key)) {
+    if (issue.severity == IrohadConfigIssue::Severity::kError) {
+      log->error("{}: {}", issue.field, issue.message);
+      is_valid = false;
+    } else {
+      log->warn("{}: {}", issue.field, issue.message);
+    }
+  }
+
+  return is_valid;
+}
+
 logger::LoggerManagerTreePtr getDefaultLogManager() {
   return std::make_shared<logger::LoggerManagerTree>(logger::LoggerConfig{
       logger::LogLevel::kInfo, logger::getDefaultLogPatterns()});
@@ -255,6 +322,15 @@ int main(int argc, char *argv[]) {
     log->info("Irohad version: {}", iroha::kGitPrettyVersion);
     log->info("config initialized");
 
+    if (FLAGS_validate_config) {
+      if (not checkConfig(config, log_manager, log)) {
+        log->error("Configuration {} is invalid", FLAGS_config);
+        return EXIT_FAILURE;
+      }
+      log->info("Configuration {} is valid", FLAGS_config);
+      return EXIT_SUCCESS;
+    }
+
     if (config.initial_peers and config.initial_peers->empty()) {
       log->critical(
           "Got an empty initial peers list in configuration file. You have to "
@@ -489,6 +565,7 @@ int main(int argc, char *argv[]) {
         metrics =
             Metrics::create(metrics_addr,
                             irohad->storage,
+                            config.block_store_path,
                             log_manager->getChild("Metrics")->getLogger());
         log->info("Metrics listens on {}", metrics->getListenAddress());
       } catch (std::exception const &ex) {
@@ -5,10 +5,14 @@
 
 #include "main/server_runner.hpp"
 
+#include <grpc/compression.h>
 #include <grpc/impl/codegen/grpc_types.h>
+#include <grpc/slice.h>
 
+#include <algorithm>
 #include <boost/format.hpp>
 #include <chrono>
+#include <limits>
 
 #include "logger/logger.hpp"
 #include "network/channel_constants.hpp"
@@ -16,8 +20,24 @@
 
 using namespace iroha::network;
 
+std::optional<grpc_compression_algorithm>
+iroha::network::parseCompressionAlgorithm(std::string const &name) {
+  grpc_compression_algorithm algorithm;
+  grpc_slice name_slice = grpc_slice_from_copied_string(name.c_str());
+  bool const is_known =
+      grpc_compression_algorithm_parse(name_slice, &algorithm);
+  grpc_slice_unref(name_slice);
+  if (not is_known) {
+    return std::nullopt;
+  }
+  return algorithm;
+}
+
 namespace {
 
+  /// grpc default of GRPC_ARG_HTTP2_MIN_RECV_PING_INTERVAL_WITHOUT_DATA_MS
+  constexpr int kDefaultMinRecvPingIntervalMs = 300000;
+
   std::shared_ptr<grpc::ServerCredentials> createCredentials(
       const boost::optional<std::shared_ptr<const TlsCredentials>>
           &my_tls_creds) {
@@ -34,17 +54,31 @@ namespace {
     return credentials;
   }
 
+  /**
+   * grpc takes durations in channel arguments as int milliseconds
+   */
+  bool fitsChannelArgument(std::chrono::milliseconds value) {
+    return value.count() > 0
+        and value.count() <= std::numeric_limits<int>::max();
+  }
+
 }  // namespace
 
 ServerRunner::ServerRunner(
     const std::string &address,
     logger::LoggerPtr log,
     bool reuse,
-    const boost::optional<std::shared_ptr<const TlsCredentials>> &my_tls_creds)
+    const boost::optional<std::shared_ptr<const TlsCredentials>> &my_tls_creds,
+    std::optional<std::string> compression_algorithm,
+    std::optional<std::chrono::milliseconds> keepalive_time,
+    std::optional<std::chrono::milliseconds> keepalive_timeout)
     : log_(std::move(log)),
       server_address_(address),
       credentials_(createCredentials(my_tls_creds)),
-      reuse_(reuse) {}
+      reuse_(reuse),
+      compression_algorithm_(std::move(compression_algorithm)),
+      keepalive_time_(keepalive_time),
+      keepalive_timeout_(keepalive_timeout) {}
 
 ServerRunner::~ServerRunner() {
   shutdown(std::chrono::system_clock::now());
@@ -65,6 +99,44 @@ iroha::expected::Result<int, std::string> ServerRunner::run() {
   builder.SetMaxReceiveMessageSize(kMaxMessageSize);
   builder.SetMaxSendMessageSize(kMaxMessageSize);
 
+  if (compression_algorithm_) {
+    auto const algorithm = parseCompressionAlgorithm(*compression_algorithm_);
+    if (not algorithm) {
+      return iroha::expected::makeError(
+          fmt::format("Unknown compression algorithm `{}'",
+                      compression_algorithm_.value()));
+    }
+    // grpc negotiates the algorithm with each client and sends responses
+    // uncompressed to the clients which do not support it
+    builder.SetDefaultCompressionAlgorithm(*algorithm);
+  }
+
+  if (keepalive_time_) {
+    if (not fitsChannelArgument(*keepalive_time_)) {
+      return iroha::expected::makeError(fmt::format(
+          "Keepalive time {} ms is out of range", keepalive_time_->count()));
+    }
+    auto const keepalive_ms = static_cast<int>(keepalive_time_->count());
+    builder.AddChannelArgument(GRPC_ARG_KEEPALIVE_TIME_MS, keepalive_ms);
+    // streams may stay silent for a long time, keep pinging them anyway
+    builder.AddChannelArgument(GRPC_ARG_HTTP2_MAX_PINGS_WITHOUT_DATA, 0);
+    // let clients ping with the same interval, but never make it stricter
+    // than the grpc default
+    builder.AddChannelArgument(
+        GRPC_ARG_HTTP2_MIN_RECV_PING_INTERVAL_WITHOUT_DATA_MS,
+        std::min(keepalive_ms, kDefaultMinRecvPingIntervalMs));
+  }
+
+  if (keepalive_timeout_) {
+    if (not fitsChannelArgument(*keepalive_timeout_)) {
+      return iroha::expected::makeError(
+          fmt::format("Keepalive timeout {} ms is out of range",
+                      keepalive_timeout_->count()));
+    }
+    builder.AddChannelArgument(GRPC_ARG_KEEPALIVE_TIMEOUT_MS,
+                               static_cast<int>(keepalive_timeout_->count()));
+  }
+
   for (auto &service : services_) {
     builder.RegisterService(service.get());
   }
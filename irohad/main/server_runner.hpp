@@ -6,9 +6,13 @@
 #ifndef MAIN_SERVER_RUNNER_HPP
 #define MAIN_SERVER_RUNNER_HPP
 
+#include <chrono>
 #include <condition_variable>
+#include <optional>
+#include <string>
 
 #include <grpc++/grpc++.h>
+#include <grpc/compression.h>
 #include <grpc++/impl/codegen/service_type.h>
 #include "common/result.hpp"
 #include "logger/logger_fwd.hpp"
@@ -17,6 +21,14 @@ namespace iroha {
   namespace network {
     struct TlsCredentials;
 
+    /**
+     * Find a grpc compression algorithm by its name
+     * @param name - name of the algorithm, e.g. "gzip"
+     * @return the algorithm, std::nullopt if grpc does not know the name
+     */
+    std::optional<grpc_compression_algorithm> parseCompressionAlgorithm(
+        std::string const &name);
+
     /**
      * Class runs Torii server for handling queries and commands.
      */
@@ -28,13 +40,24 @@ namespace iroha {
        * @param log to print progress to
        * @param reuse - allow multiple sockets to bind to the same port
        * @param my_tls_creds - TLS credentials_ for this server, if required
+       * @param compression_algorithm - name of the grpc compression algorithm
+       * (e.g. "gzip") to use for responses, if required
+       * @param keepalive_time - interval of keepalive pings sent to clients,
+       * keeps long-lived streams alive and drops unresponsive clients
+       * @param keepalive_timeout - time to wait for a keepalive ping
+       * acknowledgement before closing the connection
        */
       explicit ServerRunner(
           const std::string &address,
           logger::LoggerPtr log,
           bool reuse = true,
           const boost::optional<std::shared_ptr<const TlsCredentials>>
-              &my_tls_creds = boost::none);
+              &my_tls_creds = boost::none,
+          std::optional<std::string> compression_algorithm = std::nullopt,
+          std::optional<std::chrono::milliseconds> keepalive_time =
+              std::nullopt,
+          std::optional<std::chrono::milliseconds> keepalive_timeout =
+              std::nullopt);
 
       ~ServerRunner();
 
@@ -76,6 +99,9 @@ namespace iroha {
       std::string server_address_;
       std::shared_ptr<grpc::ServerCredentials> credentials_;
       bool reuse_;
+      std::optional<std::string> compression_algorithm_;
+      std::optional<std::chrono::milliseconds> keepalive_time_;
+      std::optional<std::chrono::milliseconds> keepalive_timeout_;
       std::vector<std::shared_ptr<grpc::Service>> services_;
     };
 
@@ -61,6 +61,10 @@ namespace iroha {
     kOnMstExpiredBatches,
     kOnMstMetrics,
 
+    // Ordering service
+    kOnPendingBatchesMetrics,
+    kOnPendingBatchesWaitTimes,
+
     // YAC
     kTimer,
     kOnState,
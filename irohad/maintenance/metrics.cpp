@@ -7,12 +7,14 @@
 
 #include <prometheus/counter.h>
 #include <prometheus/exposer.h>
+#include <prometheus/histogram.h>
 #include <prometheus/registry.h>
 
 #include <memory>
 #include <regex>
 
 #include "CivetServer.h"  // for CivetCallbacks
+#include "common/files.hpp"
 #include "interfaces/commands/add_peer.hpp"
 #include "interfaces/commands/command.hpp"
 #include "interfaces/commands/create_domain.hpp"
@@ -25,8 +27,14 @@
 using namespace iroha;
 using namespace prometheus;
 
+namespace {
+  /// How often the block store directory is rescanned for its size.
+  constexpr auto kBlockStoreScanPeriod = std::chrono::seconds(60);
+}  // namespace
+
 Metrics::Metrics(std::string const &listen_addr,
                  std::shared_ptr<iroha::ametsuchi::Storage> storage,
+                 boost::optional<std::string> const &block_store_path,
                  logger::LoggerPtr const &logger)
     : storage_(storage),
       logger_(logger),
@@ -109,6 +117,15 @@ Metrics::Metrics(std::string const &listen_addr,
           .assumeValue();
   number_of_signatures_in_last_block.Set(boost::size(ptopblock->signatures()));
 
+  // Blocks are kept in the database when block store path is not set, then
+  // their size is a part of rdb_sst_files_size for RocksDB.
+  auto &block_store_size_gauge =
+      BuildGauge()
+          .Name("block_store_size_bytes")
+          .Help("Size of the block store files on disk, in bytes")
+          .Register(*registry_);
+  auto &block_store_size = block_store_size_gauge.Add({});
+
   block_subscriber_ =
       SubscriberCreator<bool, BlockPtr>::template create<EventTypes::kOnBlock>(
           SubscriptionEngineHandlers::kMetrics,
@@ -186,6 +203,49 @@ Metrics::Metrics(std::string const &listen_addr,
         number_of_pending_mst_transactions.Set(std::get<1>(mstmetr));
       });
 
+  auto &number_of_pending_batches =
+      BuildGauge()
+          .Name("number_of_pending_batches")
+          .Help("Number of batches waiting for a proposal")
+          .Register(*registry_)
+          .Add({});
+
+  auto &number_of_pending_transactions =
+      BuildGauge()
+          .Name("number_of_pending_transactions")
+          .Help("Number of transactions waiting for a proposal")
+          .Register(*registry_)
+          .Add({});
+
+  pending_batches_subscriber_ =
+      SubscriberCreator<bool, PendingBatchesMetrics>::template create<
+          EventTypes::kOnPendingBatchesMetrics>(
+          SubscriptionEngineHandlers::kMetrics,
+          [&](auto &, PendingBatchesMetrics pending) {
+            number_of_pending_batches.Set(std::get<0>(pending));
+            number_of_pending_transactions.Set(std::get<1>(pending));
+          });
+
+  auto &pending_batch_wait_seconds =
+      BuildHistogram()
+          .Name("pending_batch_wait_seconds")
+          .Help("Time a batch waited in the ordering service for a proposal")
+          .Register(*registry_)
+          .Add({},
+               Histogram::BucketBoundaries{
+                   0.1, 0.25, 0.5, 1., 2.5, 5., 10., 30., 60., 300.});
+
+  pending_batches_wait_subscriber_ =
+      SubscriberCreator<bool, PendingBatchesWaitTimes>::template create<
+          EventTypes::kOnPendingBatchesWaitTimes>(
+          SubscriptionEngineHandlers::kMetrics,
+          [&](auto &, PendingBatchesWaitTimes wait_times) {
+            for (auto const &wait_time : wait_times) {
+              pending_batch_wait_seconds.Observe(
+                  std::chrono::duration<double>(wait_time).count());
+            }
+          });
+
   ////////////////////////////////////////////////////////////
 
   auto &param_block_cache_cap = BuildGauge()
@@ -252,17 +312,35 @@ Metrics::Metrics(std::string const &listen_addr,
   auto &uptime_ms = uptime_ms_gauge.Add({});
   uptime_ms.Set(calc_uptime_ms());
 
+  // The block store is rescanned periodically rather than on each block, so
+  // that overwritten and removed block files are accounted as well. The first
+  // scan is done when the thread starts to keep it off the startup path.
   uptime_thread_ =
       std::thread([&uptime_ms,
+                   &block_store_size,
+                   block_store_path,
                    calc_uptime_ms{std::move(calc_uptime_ms)},
                    this,
                    wregistry{std::weak_ptr<Registry>(registry_)}]() {
+        std::optional<std::chrono::steady_clock::time_point>
+            last_block_store_scan;
         // Metrics values are stored inside and owned by registry,
         // capture them by reference is legal.
         while (not this->uptime_thread_cancelation_flag_.load()) {
+          auto const now = std::chrono::steady_clock::now();
+          std::optional<uintmax_t> block_store_size_value;
+          if (block_store_path
+              and (not last_block_store_scan
+                   or now - *last_block_store_scan >= kBlockStoreScanPeriod)) {
+            block_store_size_value = iroha::directorySize(*block_store_path);
+            last_block_store_scan = now;
+          }
           {
             std::shared_ptr<Registry> registry{wregistry};  // throw if expired
             uptime_ms.Set(calc_uptime_ms());
+            if (block_store_size_value) {
+              block_store_size.Set(*block_store_size_value);
+            }
           }  // unlock registry at this point
           std::this_thread::sleep_for(std::chrono::seconds(1));
         }
@@ -9,11 +9,14 @@
 #include <prometheus/exposer.h>
 #include <prometheus/registry.h>
 
+#include <boost/optional.hpp>
 #include <chrono>
 #include <memory>
 #include <optional>
 #include <string>
 #include <thread>
+#include <tuple>
+#include <vector>
 
 #include "ametsuchi/storage.hpp"
 #include "ametsuchi/wsv_query.hpp"
@@ -33,6 +36,14 @@ class Metrics : public std::enable_shared_from_this<Metrics> {
   using BlockSubscriber = iroha::BaseSubscriber<bool, BlockPtr>;
   using MstMetrics = std::tuple<size_t, size_t>;
   using MstSubscriber = iroha::BaseSubscriber<bool, MstMetrics>;
+  /// numbers of batches and transactions waiting for a proposal
+  using PendingBatchesMetrics = std::tuple<size_t, size_t>;
+  using PendingBatchesSubscriber =
+      iroha::BaseSubscriber<bool, PendingBatchesMetrics>;
+  /// how long the proposed batches waited for a proposal
+  using PendingBatchesWaitTimes = std::vector<std::chrono::milliseconds>;
+  using PendingBatchesWaitSubscriber =
+      iroha::BaseSubscriber<bool, PendingBatchesWaitTimes>;
   using RdbSubscriber = iroha::BaseSubscriber<bool, iroha::RocksDbStatus>;
 
   std::string listen_addr_port_;
@@ -41,6 +52,9 @@ class Metrics : public std::enable_shared_from_this<Metrics> {
   std::shared_ptr<iroha::ametsuchi::Storage> storage_;
   std::shared_ptr<BlockSubscriber> block_subscriber_;
   std::shared_ptr<MstSubscriber> mst_subscriber_;
+  std::shared_ptr<PendingBatchesSubscriber> pending_batches_subscriber_;
+  std::shared_ptr<PendingBatchesWaitSubscriber>
+      pending_batches_wait_subscriber_;
   std::shared_ptr<RdbSubscriber> rdb_subscriber_;
   logger::LoggerPtr logger_;
   std::chrono::steady_clock::time_point uptime_start_timepoint_;
@@ -51,6 +65,7 @@ class Metrics : public std::enable_shared_from_this<Metrics> {
 
   Metrics(std::string const &listen_addr,
           std::shared_ptr<iroha::ametsuchi::Storage> storage,
+          boost::optional<std::string> const &block_store_path,
           logger::LoggerPtr const &logger);
 
   ~Metrics();
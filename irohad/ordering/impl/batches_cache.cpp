@@ -170,6 +170,7 @@ namespace iroha::ordering {
         if (mergeSignaturesInBatch(it_batch->second.batch, batch)) {
           if (it_batch->second.batch->hasAllSignatures()) {
             batches_cache_.insert(it_batch->second.batch);
+            markPending(it_batch->second.batch);
             mst_state -= it_batch;
             notifyEngine(std::make_tuple(
                 std::make_pair(EventTypes::kOnMstPreparedBatches,
@@ -231,14 +232,17 @@ namespace iroha::ordering {
 
     if (batch->hasAllSignatures()) {
       if (used_batches_cache_.getBatchesSet().find(batch)
-          == used_batches_cache_.getBatchesSet().end())
+          == used_batches_cache_.getBatchesSet().end()) {
         batches_cache_.insert(batch);
+        markPending(batch);
+      }
       removeMSTCache(batch);
       notifyEngine(std::make_tuple(
           std::make_pair(EventTypes::kOnMstPreparedBatches, batch)));
     } else
       insertMSTCache(batch);
 
+    notifyPendingMetrics();
     return batches_cache_.getTxsCount();
   }
 
@@ -251,12 +255,21 @@ namespace iroha::ordering {
     assert(used_batches_cache_.getTxsCount() == 0ull);
 
     batches_cache_.remove([&](auto &batch, bool & /*process_iteration*/) {
-      return std::any_of(batch->transactions().begin(),
-                         batch->transactions().end(),
-                         [&hashes](const auto &tx) {
-                           return hashes.find(tx->hash()) != hashes.end();
-                         });
+      auto const committed =
+          std::any_of(batch->transactions().begin(),
+                      batch->transactions().end(),
+                      [&hashes](const auto &tx) {
+                        return hashes.find(tx->hash()) != hashes.end();
+                      });
+      if (committed) {
+        pending_since_.erase(batch->reducedHash());
+      } else {
+        // batches of rejected proposals are pending again
+        markPending(batch);
+      }
+      return committed;
     });
+    notifyPendingMetrics();
   }
 
   bool BatchesCache::isEmpty() {
@@ -284,10 +297,51 @@ namespace iroha::ordering {
       OnDemandOrderingService::CollectionType batches) {
     /// TODO(iceseer): batches push by reference
     std::unique_lock lock(batches_cache_cs_);
+    std::vector<std::shared_ptr<shared_model::interface::TransactionBatch>>
+        proposed;
     for (auto &batch : batches) {
-      batches_cache_.removeBatch(batch);
+      if (batches_cache_.removeBatch(batch))
+        proposed.push_back(batch);
       used_batches_cache_.insert(batch);
     }
+    onBatchesProposed(proposed);
+  }
+
+  void BatchesCache::markPending(
+      std::shared_ptr<shared_model::interface::TransactionBatch> const
+          &batch) {
+    pending_since_.try_emplace(batch->reducedHash(),
+                               std::chrono::steady_clock::now());
+  }
+
+  void BatchesCache::notifyPendingMetrics() {
+    notifyEngine(std::make_tuple(std::make_pair(
+        EventTypes::kOnPendingBatchesMetrics,
+        std::make_tuple(batches_cache_.getBatchesSet().size(),
+                        static_cast<size_t>(batches_cache_.getTxsCount())))));
+  }
+
+  void BatchesCache::onBatchesProposed(
+      std::vector<std::shared_ptr<shared_model::interface::TransactionBatch>>
+          const &batches) {
+    if (batches.empty())
+      return;
+
+    auto const now = std::chrono::steady_clock::now();
+    std::vector<std::chrono::milliseconds> wait_times;
+    wait_times.reserve(batches.size());
+    for (auto const &batch : batches) {
+      if (auto it = pending_since_.find(batch->reducedHash());
+          it != pending_since_.end()) {
+        wait_times.push_back(
+            std::chrono::duration_cast<std::chrono::milliseconds>(
+                now - it->second));
+        pending_since_.erase(it);
+      }
+    }
+    notifyEngine(std::make_tuple(std::make_pair(
+        EventTypes::kOnPendingBatchesWaitTimes, std::move(wait_times))));
+    notifyPendingMetrics();
   }
 
 }  // namespace iroha::ordering
@@ -8,6 +8,7 @@
 
 #include "ordering/on_demand_ordering_service.hpp"
 
+#include <chrono>
 #include <map>
 #include <memory>
 #include <numeric>
@@ -18,6 +19,7 @@
 
 #include "common/common.hpp"
 #include "consensus/round.hpp"
+#include "interfaces/iroha_internal/transaction_batch_helpers.hpp"
 #include "ordering/ordering_types.hpp"
 
 namespace shared_model::interface {
@@ -151,8 +153,15 @@ namespace iroha::ordering {
       }
     };
 
+    using PendingSinceType =
+        std::unordered_map<shared_model::interface::types::HashType,
+                           std::chrono::steady_clock::time_point,
+                           shared_model::crypto::Hash::Hasher>;
+
     mutable std::shared_mutex batches_cache_cs_;
     BatchesContext batches_cache_, used_batches_cache_;
+    /// time when each batch in batches_cache_ became available for proposal
+    PendingSinceType pending_since_;
 
     std::shared_ptr<utils::ReadWriteObject<MSTState, std::mutex>> mst_state_;
 
@@ -167,6 +176,18 @@ namespace iroha::ordering {
             &batch);
     void removeMSTCache(OnDemandOrderingService::HashesSetType const &hashes);
 
+    /**
+     * Pending batches metrics functions, must be called under
+     * batches_cache_cs_
+     */
+    void markPending(
+        std::shared_ptr<shared_model::interface::TransactionBatch> const
+            &batch);
+    void onBatchesProposed(
+        std::vector<std::shared_ptr<shared_model::interface::TransactionBatch>>
+            const &batches);
+    void notifyPendingMetrics();
+
    public:
     BatchesCache(BatchesCache const &) = delete;
     BatchesCache &operator=(BatchesCache const &) = delete;
@@ -186,6 +207,7 @@ namespace iroha::ordering {
     template <typename IsProcessedFunc>
     void getTransactions(
         size_t requested_tx_amount,
+        size_t requested_bytes,
         std::vector<std::shared_ptr<shared_model::interface::Transaction>>
             &collection,
         BloomFilter256 &bf,
@@ -196,12 +218,24 @@ namespace iroha::ordering {
 
       std::unique_lock lock(batches_cache_cs_);
       uint32_t depth_counter = 0ul;
+      size_t collection_bytes = 0ull;
+      std::vector<std::shared_ptr<shared_model::interface::TransactionBatch>>
+          proposed;
       batches_cache_.remove([&](auto &batch, bool &process_iteration) {
-        if (std::forward<IsProcessedFunc>(is_processed)(batch))
+        if (std::forward<IsProcessedFunc>(is_processed)(batch)) {
+          pending_since_.erase(batch->reducedHash());
           return true;
+        }
 
         auto const txs_count = batch->transactions().size();
-        if (collection.size() + txs_count > requested_tx_amount) {
+        auto const batch_bytes = shared_model::interface::
+            TransactionBatchHelpers::calculateBatchBlobSize(
+                batch->transactions());
+        // a batch bigger than requested_bytes on its own (e.g. received from
+        // a peer with a higher limit) still gets a proposal of its own
+        if (collection.size() + txs_count > requested_tx_amount
+            or (not collection.empty()
+                and collection_bytes + batch_bytes > requested_bytes)) {
           ++depth_counter;
           process_iteration = (depth_counter < 8ull);
           return false;
@@ -214,10 +248,14 @@ namespace iroha::ordering {
                           std::begin(batch->transactions()),
                           std::end(batch->transactions()));
 
+        collection_bytes += batch_bytes;
+
         bf.set(batch->reducedHash());
         used_batches_cache_.insert(batch);
+        proposed.push_back(batch);
         return true;
       });
+      onBatchesProposed(proposed);
     }
 
     void processReceivedProposal(
@@ -5,6 +5,7 @@
 
 #include "ordering/impl/on_demand_ordering_service_impl.hpp"
 
+#include <limits>
 #include <string_view>
 #include <unordered_set>
 
@@ -70,9 +71,12 @@ OnDemandOrderingServiceImpl::OnDemandOrderingServiceImpl(
         proposal_factory,
     std::shared_ptr<ametsuchi::TxPresenceCache> tx_cache,
     logger::LoggerPtr log,
+    std::optional<size_t> max_proposal_bytes,
     size_t number_of_proposals)
     : transaction_limit_(transaction_limit),
       number_of_proposals_(number_of_proposals),
+      max_proposal_bytes_(
+          max_proposal_bytes.value_or(std::numeric_limits<size_t>::max())),
       max_proposal_pack_(max_proposal_pack),
       proposal_factory_(std::move(proposal_factory)),
       tx_cache_(std::move(tx_cache)),
@@ -308,7 +312,11 @@ OnDemandOrderingServiceImpl::packNextProposals(const consensus::Round &round) {
   for (uint32_t ix = 0; ix < number_of_proposals; ++ix) {
     assert(!isEmptyBatchesCache());
     batches_cache_.getTransactions(
-        transaction_limit_, txs, bf, [&](auto const &batch) {
+        transaction_limit_,
+        max_proposal_bytes_,
+        txs,
+        bf,
+        [&](auto const &batch) {
           assert(batch);
           return batchAlreadyProcessed(*batch);
         });
@@ -10,6 +10,7 @@
 
 #include <map>
 #include <mutex>
+#include <optional>
 #include <shared_mutex>
 
 #include "interfaces/iroha_internal/unsafe_proposal_factory.hpp"
@@ -38,6 +39,8 @@ namespace iroha {
        * @param proposal_factory - used to generate proposals
        * @param tx_cache - cache of transactions
        * @param log to print progress
+       * @param max_proposal_bytes - maximum summary size of transactions in one
+       * proposal in bytes, unlimited if not set
        * @param number_of_proposals - number of stored proposals, older will be
        * removed. Default value is 3
        */
@@ -48,6 +51,7 @@ namespace iroha {
               proposal_factory,
           std::shared_ptr<ametsuchi::TxPresenceCache> tx_cache,
           logger::LoggerPtr log,
+          std::optional<size_t> max_proposal_bytes = std::nullopt,
           size_t number_of_proposals = 3);
 
       ~OnDemandOrderingServiceImpl() override;
@@ -131,6 +135,11 @@ namespace iroha {
        */
       size_t number_of_proposals_;
 
+      /**
+       * Max summary size of transactions in one proposal in bytes
+       */
+      size_t max_proposal_bytes_;
+
       /**
        * Maximum proposals count in a pack.
        */
@@ -9,6 +9,7 @@
 #include "common/byteutils.hpp"
 #include "common/visitor.hpp"
 #include "interfaces/iroha_internal/transaction_batch.hpp"
+#include "interfaces/iroha_internal/transaction_batch_helpers.hpp"
 #include "interfaces/transaction.hpp"
 #include "interfaces/transaction_responses/not_received_tx_response.hpp"
 #include "logger/logger.hpp"
@@ -22,12 +23,14 @@ CommandServiceImpl::CommandServiceImpl(
     std::shared_ptr<shared_model::interface::TxStatusFactory> status_factory,
     std::shared_ptr<iroha::torii::CommandServiceImpl::CacheType> cache,
     std::shared_ptr<iroha::ametsuchi::TxPresenceCache> tx_presence_cache,
-    logger::LoggerPtr log)
+    logger::LoggerPtr log,
+    std::optional<size_t> max_proposal_bytes)
     : tx_processor_(std::move(tx_processor)),
       status_bus_(std::move(status_bus)),
       cache_(std::move(cache)),
       status_factory_(std::move(status_factory)),
       tx_presence_cache_(std::move(tx_presence_cache)),
+      max_proposal_bytes_(max_proposal_bytes),
       log_(std::move(log)) {}
 
 void CommandServiceImpl::handleTransactionBatch(
@@ -131,6 +134,29 @@ void CommandServiceImpl::processBatch(
     return;
   }
 
+  if (max_proposal_bytes_) {
+    auto const batch_bytes =
+        shared_model::interface::TransactionBatchHelpers::
+            calculateBatchBlobSize(txs);
+    if (batch_bytes > *max_proposal_bytes_) {
+      // such batch would never fit into a proposal
+      auto const message =
+          fmt::format("Batch size {} bytes exceeds max_proposal_bytes {}",
+                      batch_bytes,
+                      *max_proposal_bytes_);
+      log_->warn("{}. {}", message, *batch);
+      for (auto const &tx : txs) {
+        this->pushStatus(
+            status_issuer,
+            status_factory_->makeStatelessFail(
+                tx->hash(),
+                shared_model::interface::TxStatusFactory::TransactionError{
+                    message, 0, 0}));
+      }
+      return;
+    }
+  }
+
   auto cache_presence = tx_presence_cache_->check(*batch);
   if (not cache_presence) {
     // TODO andrei 30.11.18 IR-51 Handle database error
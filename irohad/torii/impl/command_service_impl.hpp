@@ -8,6 +8,8 @@
 
 #include "torii/command_service.hpp"
 
+#include <optional>
+
 #include "ametsuchi/storage.hpp"
 #include "ametsuchi/tx_presence_cache.hpp"
 #include "cache/cache.hpp"
@@ -37,6 +39,8 @@ namespace iroha::torii {
      * CommandServiceImpl::CacheType
      * @param tx_presence_cache a cache over persistent storage
      * @param log to print progress
+     * @param max_proposal_bytes - maximum summary size of transactions in one
+     * proposal in bytes, bigger batches are rejected. Unlimited if not set
      */
     CommandServiceImpl(
         std::shared_ptr<iroha::torii::TransactionProcessor> tx_processor,
@@ -45,7 +49,8 @@ namespace iroha::torii {
             status_factory,
         std::shared_ptr<iroha::torii::CommandServiceImpl::CacheType> cache,
         std::shared_ptr<iroha::ametsuchi::TxPresenceCache> tx_presence_cache,
-        logger::LoggerPtr log);
+        logger::LoggerPtr log,
+        std::optional<size_t> max_proposal_bytes = std::nullopt);
 
     /**
      * Disable copying in any way to prevent potential issues with common
@@ -88,6 +93,7 @@ namespace iroha::torii {
     std::shared_ptr<CacheType> cache_;
     std::shared_ptr<shared_model::interface::TxStatusFactory> status_factory_;
     std::shared_ptr<iroha::ametsuchi::TxPresenceCache> tx_presence_cache_;
+    std::optional<size_t> max_proposal_bytes_;
 
     logger::LoggerPtr log_;
   };
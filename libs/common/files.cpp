@@ -70,3 +70,19 @@ iroha::readBinaryFile(const boost::filesystem::path &path) {
   return readFile<std::vector<uint8_t>>(
       path, std::ios_base::binary | std::ios_base::in);
 }
+
+uintmax_t iroha::directorySize(const boost::filesystem::path &dir) {
+  uintmax_t size = 0;
+  boost::system::error_code error_code;
+  for (boost::filesystem::directory_iterator it(dir, error_code), end;
+       not error_code and it != end;
+       it.increment(error_code)) {
+    boost::system::error_code file_error_code;
+    auto const file_size =
+        boost::filesystem::file_size(it->path(), file_error_code);
+    if (not file_error_code) {
+      size += file_size;
+    }
+  }
+  return size;
+}
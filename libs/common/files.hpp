@@ -6,6 +6,7 @@
 #ifndef IROHA_FILES_HPP
 #define IROHA_FILES_HPP
 
+#include <cstdint>
 #include <string>
 #include <vector>
 
@@ -42,5 +43,13 @@ namespace iroha {
    */
   iroha::expected::Result<std::vector<uint8_t>, std::string> readBinaryFile(
       const boost::filesystem::path &path);
+
+  /**
+   * Calculate total size of the regular files directly inside a folder.
+   * Files which cannot be accessed are skipped.
+   * @param dir - target folder
+   * @return summary size in bytes, 0 if the folder cannot be read
+   */
+  uintmax_t directorySize(const boost::filesystem::path &dir);
 }  // namespace iroha
 #endif  // IROHA_FILES_HPP
@@ -6,6 +6,7 @@
 #ifndef IROHA_TRANSACTION_BATCH_HELPERS_HPP
 #define IROHA_TRANSACTION_BATCH_HELPERS_HPP
 
+#include <numeric>
 #include <sstream>
 
 #include "cryptography/hash.hpp"
@@ -14,7 +15,7 @@ namespace shared_model {
   namespace interface {
 
     /**
-     * Provides a method that calculates reduced batch hash
+     * Provides methods that calculate reduced batch hash and batch size
      */
     class TransactionBatchHelpers {
      public:
@@ -34,6 +35,21 @@ namespace shared_model {
         }
         return types::HashType::fromHexString(concatenated_hash.str());
       }
+
+      /**
+       * Get the total size of serialized transactions of a batch
+       * @tparam Collection type of const ref iterator
+       * @param transactions of the batch
+       * @return sum of transaction blob sizes in bytes
+       */
+      template <typename Collection>
+      static size_t calculateBatchBlobSize(const Collection &transactions) {
+        return std::accumulate(
+            transactions.begin(),
+            transactions.end(),
+            size_t{0},
+            [](size_t sum, const auto &tx) { return sum + tx->blob().size(); });
+      }
     };
   }  // namespace interface
 }  // namespace shared_model
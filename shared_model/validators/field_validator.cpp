@@ -116,14 +116,31 @@ namespace {
       "EvmHexAddress",
       R"#([0-9a-fA-F]{40})#",
       "Hex encoded 20-byte address expected"};
+
+  /**
+   * @return future gap in milliseconds: the explicit one if set, otherwise
+   * the configured one if set, otherwise the default one
+   */
+  time_t getFutureGap(std::optional<time_t> future_gap,
+                      shared_model::validation::ValidatorsConfig const &config) {
+    if (future_gap) {
+      return *future_gap;
+    }
+    if (config.max_future_created_seconds) {
+      return std::chrono::seconds(*config.max_future_created_seconds)
+          / std::chrono::milliseconds(1);
+    }
+    return shared_model::validation::FieldValidator::kDefaultFutureGap;
+  }
 }  // namespace
 
 namespace shared_model {
   namespace validation {
     FieldValidator::FieldValidator(std::shared_ptr<ValidatorsConfig> config,
-                                   time_t future_gap,
+                                   std::optional<time_t> future_gap,
                                    TimeFunction time_provider)
-        : future_gap_(future_gap), time_provider_(time_provider),
+        : future_gap_(getFutureGap(future_gap, *config)),
+          time_provider_(time_provider),
           max_delay_(config->max_past_created_hours ?
                          std::chrono::hours(config->max_past_created_hours.value()) / std::chrono::milliseconds(1)
                                                     : kDefaultMaxDelay)
@@ -46,8 +46,15 @@ namespace shared_model {
      public:
       // todo igor-egorov 05.04.2018 IR-439 Remove ValidatorsConfig from
       // FieldValidator
+      /**
+       * @param config - validators configuration
+       * @param future_gap - gap for future transactions in milliseconds, if
+       * set it takes precedence over `config->max_future_created_seconds`,
+       * otherwise the config value or `kDefaultFutureGap` is used
+       * @param time_provider - current time callback
+       */
       FieldValidator(std::shared_ptr<ValidatorsConfig> config,
-                     time_t future_gap = kDefaultFutureGap,
+                     std::optional<time_t> future_gap = std::nullopt,
                      TimeFunction time_provider = [] {
                        return iroha::time::now();
                      });
@@ -14,11 +14,13 @@ namespace shared_model {
     ValidatorsConfig::ValidatorsConfig(uint64_t max_batch_size,
                                        bool partial_ordered_batches_are_valid,
                                        bool txs_duplicates_allowed,
-                                       std::optional<uint32_t> max_past_created_hours)
+                                       std::optional<uint32_t> max_past_created_hours,
+                                       std::optional<uint32_t> max_future_created_seconds)
         : max_batch_size(max_batch_size),
           partial_ordered_batches_are_valid(partial_ordered_batches_are_valid),
           txs_duplicates_allowed(txs_duplicates_allowed),
-          max_past_created_hours(max_past_created_hours)
+          max_past_created_hours(max_past_created_hours),
+          max_future_created_seconds(max_future_created_seconds)
     {}
 
     bool validateHexString(const std::string &str) {
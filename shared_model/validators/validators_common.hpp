@@ -21,7 +21,8 @@ namespace shared_model {
       ValidatorsConfig(uint64_t max_batch_size,
                        bool partial_ordered_batches_are_valid = false,
                        bool txs_duplicates_allowed = false,
-                       std::optional<uint32_t> max_past_created_hours = {});
+                       std::optional<uint32_t> max_past_created_hours = {},
+                       std::optional<uint32_t> max_future_created_seconds = {});
       /// Maximum allowed amount of transactions within a batch
       const uint64_t max_batch_size;
 
@@ -45,6 +46,16 @@ namespace shared_model {
        * The value must be synchronised across all peers.
        */
       std::optional<uint32_t> max_past_created_hours;
+
+      /**
+       * A parameter, which specifies how many seconds after the current
+       * peer's time can the transaction's `created_time` be set, to tolerate
+       * clock skew between clients and peers.
+       * Default is `FieldValidator::kDefaultFutureGap` (5 minutes). A gap
+       * passed explicitly to the `FieldValidator` constructor overrides it.
+       * The value must be synchronised across all peers.
+       */
+      std::optional<uint32_t> max_future_created_seconds;
     };
 
     /**
@@ -477,8 +477,8 @@ namespace iroha {
       checkSuccessfulResult<shared_model::interface::RolesResponse>(
           std::move(result), [](const auto &cast_resp) {
             ASSERT_EQ(cast_resp.roles().size(), 2);
-            ASSERT_EQ(cast_resp.roles()[0], "role");
-            ASSERT_EQ(cast_resp.roles()[1], "perms");
+            ASSERT_EQ(cast_resp.roles()[0], "perms");
+            ASSERT_EQ(cast_resp.roles()[1], "role");
           });
     }
 
@@ -508,8 +508,34 @@ namespace iroha {
       checkSuccessfulResult<shared_model::interface::RolesResponse>(
           std::move(result), [](const auto &cast_resp) {
             ASSERT_EQ(cast_resp.roles().size(), 2);
-            ASSERT_EQ(cast_resp.roles()[0], "role");
-            ASSERT_EQ(cast_resp.roles()[1], "perms");
+            ASSERT_EQ(cast_resp.roles()[0], "perms");
+            ASSERT_EQ(cast_resp.roles()[1], "role");
+          });
+    }
+
+    class GetSignatoriesExecutorTest : public QueryExecutorTest {};
+
+    /**
+     * @given initialized storage, account with several signatories added not
+     * in key order
+     * @when get signatories of the account
+     * @then signatories are returned sorted by public key
+     */
+    TEST_F(GetSignatoriesExecutorTest, SortedByPublicKey) {
+      addPerms({shared_model::interface::permissions::Role::kGetMySignatories});
+      execute(*mock_command_factory->constructAddSignatory(kPublicKey2,
+                                                           account_id),
+              true);
+      auto query = TestQueryBuilder()
+                       .creatorAccountId(account_id)
+                       .getSignatories(account_id)
+                       .build();
+      auto result = executeQuery(query);
+      checkSuccessfulResult<shared_model::interface::SignatoriesResponse>(
+          std::move(result), [](const auto &cast_resp) {
+            ASSERT_EQ(cast_resp.keys().size(), 2);
+            ASSERT_EQ(cast_resp.keys()[0], std::string_view{kPublicKey2});
+            ASSERT_EQ(cast_resp.keys()[1], std::string_view{kPublicKey});
           });
     }
 
@@ -2094,5 +2120,35 @@ namespace iroha {
           });
     }
 
+    /**
+     * @given initialized storage, permission to get peers, a peer and a
+     * syncing peer added with public keys lower than the initial one
+     * @when get peers query issued
+     * @then all peers are returned sorted by public key
+     */
+    TEST_F(GetPeersExecutorTest, SortedByPublicKey) {
+      addPerms({shared_model::interface::permissions::Role::kGetPeers});
+      shared_model::plain::Peer second_peer{
+          "127.0.0.2", std::string(64, 'b'), std::nullopt, false};
+      shared_model::plain::Peer syncing_peer{
+          "127.0.0.3", std::string(64, 'a'), std::nullopt, true};
+      execute(*mock_command_factory->constructAddPeer(second_peer), true);
+      execute(*mock_command_factory->constructAddPeer(syncing_peer), true);
+      auto query =
+          TestQueryBuilder().creatorAccountId(account_id).getPeers().build();
+      auto result = executeQuery(query);
+      checkSuccessfulResult<shared_model::interface::PeersResponse>(
+          std::move(result), [&](const auto &cast_resp) {
+            std::vector<std::string> pubkeys;
+            for (const auto &resp_peer : cast_resp.peers()) {
+              pubkeys.push_back(resp_peer.pubkey());
+            }
+            ASSERT_EQ(pubkeys,
+                      (std::vector<std::string>{syncing_peer.pubkey(),
+                                                second_peer.pubkey(),
+                                                peer.pubkey()}));
+          });
+    }
+
   }  // namespace ametsuchi
 }  // namespace iroha
@@ -0,0 +1,214 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "main/iroha_conf_loader.hpp"
+
+#include <algorithm>
+#include <cstdlib>
+
+#include <gmock/gmock.h>
+#include <gtest/gtest.h>
+#include "common/result.hpp"
+#include "main/iroha_conf_literals.hpp"
+#include "module/shared_model/interface_mocks.hpp"
+
+using Severity = IrohadConfigIssue::Severity;
+using shared_model::interface::types::PublicKeyHexStringView;
+
+class IrohadConfigCheckTest : public ::testing::Test {
+ public:
+  void SetUp() override {
+    config.torii_port = 50051;
+    config.internal_port = 10001;
+    config.database_config = IrohadConfig::DbConfig{};
+    config.crypto = IrohadConfig::Crypto{};
+    config.crypto->signer = "default";
+    config.crypto->providers.emplace(
+        "default",
+        IrohadConfig::Crypto::Default{iroha::multihash::Type::ed25519_sha3_256,
+                                      std::string(64, 'a')});
+  }
+
+  /**
+   * @return issues found in the config with the given field
+   */
+  std::vector<IrohadConfigIssue> issuesOf(
+      std::string_view field,
+      std::string_view keypair_name = {},
+      std::optional<std::string_view> own_public_key = std::nullopt) {
+    auto issues = checkIrohadConfig(config, keypair_name, own_public_key);
+    issues.erase(std::remove_if(issues.begin(),
+                                issues.end(),
+                                [&](auto const &issue) {
+                                  return issue.field != field;
+                                }),
+                 issues.end());
+    return issues;
+  }
+
+  IrohadConfig config{};
+  std::string const own_key{"own_key"};
+};
+
+/**
+ * @given a consistent config
+ * @when it is checked
+ * @then no issues are reported
+ */
+TEST_F(IrohadConfigCheckTest, ValidConfig) {
+  config.initial_peers = shared_model::interface::types::PeerList{
+      makePeer("127.0.0.1:10001", PublicKeyHexStringView{own_key})};
+  EXPECT_TRUE(checkIrohadConfig(config, {}, own_key).empty());
+}
+
+/**
+ * @given a config with the same torii and internal ports
+ * @when it is checked
+ * @then an error is reported for the internal port
+ */
+TEST_F(IrohadConfigCheckTest, PortClash) {
+  config.internal_port = config.torii_port;
+  auto issues = issuesOf(config_members::InternalPort);
+  ASSERT_EQ(1, issues.size());
+  EXPECT_EQ(Severity::kError, issues[0].severity);
+}
+
+/**
+ * @given a config with an empty initial peers list
+ * @when it is checked
+ * @then an error is reported for initial peers
+ */
+TEST_F(IrohadConfigCheckTest, EmptyInitialPeers) {
+  config.initial_peers = shared_model::interface::types::PeerList{};
+  auto issues = issuesOf(config_members::InitialPeers, {}, own_key);
+  ASSERT_EQ(1, issues.size());
+  EXPECT_EQ(Severity::kError, issues[0].severity);
+}
+
+/**
+ * @given a config without crypto section
+ * @when it is checked without keypair file name
+ * @then an error is reported for the crypto section
+ * @and it is not reported if keypair file name is given
+ */
+TEST_F(IrohadConfigCheckTest, MissingKeypair) {
+  config.crypto = boost::none;
+  auto issues = issuesOf(config_members::kCrypto);
+  ASSERT_EQ(1, issues.size());
+  EXPECT_EQ(Severity::kError, issues[0].severity);
+
+  EXPECT_TRUE(issuesOf(config_members::kCrypto, "node0").empty());
+}
+
+/**
+ * @given a config with a signer private key which is not a hex string
+ * @when it is checked without keypair file name
+ * @then an error is reported for the private key field
+ */
+TEST_F(IrohadConfigCheckTest, MalformedPrivateKey) {
+  config.crypto->providers.at("default").private_key = "zz";
+  auto issues = issuesOf("crypto/providers/default/private_key");
+  ASSERT_EQ(1, issues.size());
+  EXPECT_EQ(Severity::kError, issues[0].severity);
+  EXPECT_THAT(issues[0].message, ::testing::HasSubstr("hex"));
+
+  EXPECT_TRUE(
+      issuesOf("crypto/providers/default/private_key", "node0").empty());
+}
+
+/**
+ * @given a config with a signer which is not among the crypto providers
+ * @when it is checked without keypair file name
+ * @then an error is reported for the signer field
+ */
+TEST_F(IrohadConfigCheckTest, UnknownSigner) {
+  config.crypto->signer = "unknown";
+  auto issues = issuesOf("crypto/signer");
+  ASSERT_EQ(1, issues.size());
+  EXPECT_EQ(Severity::kError, issues[0].severity);
+}
+
+/**
+ * @given a config with initial peers not containing own public key
+ * @when it is checked
+ * @then a warning is reported for initial peers
+ */
+TEST_F(IrohadConfigCheckTest, OwnKeyNotListed) {
+  std::string const other_key{"other_key"};
+  config.initial_peers = shared_model::interface::types::PeerList{
+      makePeer("127.0.0.1:10001", PublicKeyHexStringView{other_key})};
+  auto issues = issuesOf(config_members::InitialPeers, {}, own_key);
+  ASSERT_EQ(1, issues.size());
+  EXPECT_EQ(Severity::kWarning, issues[0].severity);
+}
+
+class IrohadConfigLoadTest : public ::testing::Test {
+ public:
+  void SetUp() override {
+    // the required fields preceding the checked ones
+    setEnv("IROHA_TORII_PORT", "50051");
+    setEnv("IROHA_INTERNAL_PORT", "10001");
+    setEnv("IROHA_MAX_PROPOSAL_SIZE", "10");
+  }
+
+  void TearDown() override {
+    for (auto const &name : env_names) {
+      unsetenv(name.c_str());
+    }
+  }
+
+  void setEnv(std::string name, std::string const &value) {
+    setenv(name.c_str(), value.c_str(), 1);
+    env_names.push_back(std::move(name));
+  }
+
+  /**
+   * Load the config from the environment
+   * @return the loading error, if any
+   */
+  boost::optional<std::string> loadError() {
+    return iroha::expected::resultToOptionalError(
+        parse_iroha_config({}, nullptr, std::nullopt));
+  }
+
+  std::vector<std::string> env_names;
+};
+
+/**
+ * @given max_proposal_bytes set to 0
+ * @when the config is loaded
+ * @then loading fails with an error about max_proposal_bytes
+ */
+TEST_F(IrohadConfigLoadTest, ZeroMaxProposalBytes) {
+  setEnv("IROHA_MAX_PROPOSAL_BYTES", "0");
+  auto error = loadError();
+  ASSERT_TRUE(error);
+  EXPECT_THAT(*error, ::testing::HasSubstr(config_members::MaxProposalBytes));
+}
+
+/**
+ * @given torii_keepalive_time which does not fit grpc channel argument
+ * @when the config is loaded
+ * @then loading fails with an error about torii_keepalive_time
+ */
+TEST_F(IrohadConfigLoadTest, KeepaliveTimeOutOfRange) {
+  setEnv("IROHA_TORII_KEEPALIVE_TIME", "3000000000");
+  auto error = loadError();
+  ASSERT_TRUE(error);
+  EXPECT_THAT(*error,
+              ::testing::HasSubstr(config_members::ToriiKeepaliveTime));
+}
+
+/**
+ * @given torii_compression with a name grpc does not know
+ * @when the config is loaded
+ * @then loading fails with an error about torii_compression
+ */
+TEST_F(IrohadConfigLoadTest, UnknownCompressionAlgorithm) {
+  setEnv("IROHA_TORII_COMPRESSION", "no_such_algorithm");
+  auto error = loadError();
+  ASSERT_TRUE(error);
+  EXPECT_THAT(*error, ::testing::HasSubstr(config_members::ToriiCompression));
+}
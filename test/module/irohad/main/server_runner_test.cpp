@@ -3,6 +3,8 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#include <limits>
+
 #include <gtest/gtest.h>
 #include <boost/format.hpp>
 
@@ -67,3 +69,92 @@ TEST(ServerRunnerTest, SamePortWithReuse) {
 }
 
 #endif  // GPR_WINDOWS
+
+/**
+ * @given a ServerRunner with a known compression algorithm
+ * @when it is run
+ * @then Result with port number is returned
+ */
+TEST(ServerRunnerTest, KnownCompressionAlgorithm) {
+  ServerRunner runner((address % 0).str(),
+                      getTestLogger("ServerRunner"),
+                      true,
+                      boost::none,
+                      std::string{"gzip"});
+  auto query_service =
+      std::make_shared<iroha::protocol::QueryService_v1::Service>();
+  auto result = runner.append(query_service).run();
+  auto port = boost::apply_visitor(port_visitor, result);
+  ASSERT_NE(0, port);
+}
+
+/**
+ * @given names of compression algorithms
+ * @when they are parsed
+ * @then known names are resolved and unknown ones are not
+ */
+TEST(ServerRunnerTest, ParseCompressionAlgorithm) {
+  auto gzip = iroha::network::parseCompressionAlgorithm("gzip");
+  ASSERT_TRUE(gzip);
+  EXPECT_EQ(GRPC_COMPRESS_GZIP, *gzip);
+  EXPECT_FALSE(iroha::network::parseCompressionAlgorithm("no_such_algorithm"));
+}
+
+/**
+ * @given a ServerRunner with an unknown compression algorithm
+ * @when it is run
+ * @then Result with error is returned
+ */
+TEST(ServerRunnerTest, UnknownCompressionAlgorithm) {
+  ServerRunner runner((address % 0).str(),
+                      getTestLogger("ServerRunner"),
+                      true,
+                      boost::none,
+                      std::string{"no_such_algorithm"});
+  auto query_service =
+      std::make_shared<iroha::protocol::QueryService_v1::Service>();
+  auto result = runner.append(query_service).run();
+  auto port = boost::apply_visitor(port_visitor, result);
+  ASSERT_EQ(0, port);
+}
+
+/**
+ * @given a ServerRunner with keepalive pings and timeout enabled
+ * @when it is run
+ * @then Result with port number is returned
+ */
+TEST(ServerRunnerTest, KeepaliveTime) {
+  ServerRunner runner((address % 0).str(),
+                      getTestLogger("ServerRunner"),
+                      true,
+                      boost::none,
+                      std::nullopt,
+                      std::chrono::milliseconds(10000),
+                      std::chrono::milliseconds(5000));
+  auto query_service =
+      std::make_shared<iroha::protocol::QueryService_v1::Service>();
+  auto result = runner.append(query_service).run();
+  auto port = boost::apply_visitor(port_visitor, result);
+  ASSERT_NE(0, port);
+}
+
+/**
+ * @given a ServerRunner with keepalive time which does not fit grpc channel
+ * argument
+ * @when it is run
+ * @then Result with error is returned
+ */
+TEST(ServerRunnerTest, KeepaliveTimeOutOfRange) {
+  ServerRunner runner(
+      (address % 0).str(),
+      getTestLogger("ServerRunner"),
+      true,
+      boost::none,
+      std::nullopt,
+      std::chrono::milliseconds(std::numeric_limits<uint32_t>::max()));
+  auto query_service =
+      std::make_shared<iroha::protocol::QueryService_v1::Service>();
+  auto result = runner.append(query_service).run();
+  auto port = boost::apply_visitor(port_visitor, result);
+  ASSERT_EQ(0, port);
+}
@@ -0,0 +1,27 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "main/impl/time_response.hpp"
+
+#include <gtest/gtest.h>
+#include <rapidjson/document.h>
+
+/**
+ * @given peer's current time
+ * @when `/time` response is made
+ * @then it is a JSON object with the time in milliseconds
+ */
+TEST(TimeResponseTest, ContainsTime) {
+  auto const now = iroha::time::now();
+  auto const response = iroha::makeTimeResponse(now);
+
+  rapidjson::Document doc;
+  doc.Parse(response.data(), response.size());
+  ASSERT_FALSE(doc.HasParseError()) << response;
+  ASSERT_TRUE(doc.IsObject());
+  ASSERT_TRUE(doc.HasMember("time"));
+  ASSERT_TRUE(doc["time"].IsUint64());
+  EXPECT_EQ(now, doc["time"].GetUint64());
+}
@@ -5,6 +5,7 @@
 
 #include <gmock/gmock.h>
 #include <gtest/gtest.h>
+#include <limits>
 #include <memory>
 
 #include "framework/crypto_literals.hpp"
@@ -204,3 +205,46 @@ TEST_F(MSTNotificationsTest, DoubleTxs) {
       addSignaturesFromKeyPairs(get_batch_2(), 0, makeKey()));
   checkEvents(1, 0, 0);
 }
+
+TEST_F(MSTNotificationsTest, PendingBatchesMetrics) {
+  std::vector<std::tuple<size_t, size_t>> pending;
+  std::vector<std::chrono::milliseconds> wait_times;
+  auto pending_subscriber = iroha::SubscriberCreator<
+      bool,
+      std::tuple<size_t, size_t>>::
+      template create<iroha::EventTypes::kOnPendingBatchesMetrics>(
+          iroha::SubscriptionEngineHandlers::kNotifications,
+          [&](auto &, std::tuple<size_t, size_t> metrics) {
+            pending.push_back(metrics);
+          });
+  auto wait_subscriber = iroha::SubscriberCreator<
+      bool,
+      std::vector<std::chrono::milliseconds>>::
+      template create<iroha::EventTypes::kOnPendingBatchesWaitTimes>(
+          iroha::SubscriptionEngineHandlers::kNotifications,
+          [&](auto &, std::vector<std::chrono::milliseconds> times) {
+            wait_times.insert(wait_times.end(), times.begin(), times.end());
+          });
+
+  auto batch = addSignaturesFromKeyPairs(
+      makeTestBatch(txBuilder(1, iroha::time::now(), 1)), 0, makeKey());
+  batches_cache_->insert(batch);
+  ASSERT_FALSE(pending.empty());
+  EXPECT_EQ(std::make_tuple(size_t{1}, size_t{1}), pending.back());
+  EXPECT_TRUE(wait_times.empty());
+
+  std::vector<std::shared_ptr<shared_model::interface::Transaction>> txs;
+  iroha::ordering::BloomFilter256 bf;
+  batches_cache_->getTransactions(
+      10,
+      std::numeric_limits<size_t>::max(),
+      txs,
+      bf,
+      [](auto const &) { return false; });
+  ASSERT_EQ(1, txs.size());
+  EXPECT_EQ(1, wait_times.size());
+  EXPECT_EQ(std::make_tuple(size_t{0}, size_t{0}), pending.back());
+
+  pending_subscriber->unsubscribe();
+  wait_subscriber->unsubscribe();
+}
@@ -6,6 +6,7 @@
 #include "ordering/impl/on_demand_ordering_service_impl.hpp"
 
 #include <memory>
+#include <optional>
 
 #include <gtest/gtest.h>
 #include "backend/protobuf/proto_proposal_factory.hpp"
@@ -51,6 +52,14 @@ class OnDemandOsTest : public ::testing::Test {
 
   void SetUp() override {
     subscription = iroha::getSubscription();
+    createOs();
+  }
+
+  /**
+   * Create ordering service with every batch new by default
+   * @param max_proposal_bytes - proposal size limit in bytes
+   */
+  void createOs(std::optional<size_t> max_proposal_bytes = std::nullopt) {
     // TODO: nickaleks IR-1811 use mock factory
     auto factory = std::make_unique<
         shared_model::proto::ProtoProposalFactory<MockProposalValidator>>(
@@ -73,6 +82,7 @@ class OnDemandOsTest : public ::testing::Test {
         std::move(factory),
         std::move(tx_cache),
         getTestLogger("OdOrderingService"),
+        max_proposal_bytes,
         proposal_limit);
   }
 
@@ -257,6 +267,7 @@ TEST_F(OnDemandOsTest, UseFactoryForProposal) {
       std::move(factory),
       std::move(tx_cache),
       getTestLogger("OdOrderingService"),
+      std::nullopt,
       proposal_limit);
 
   EXPECT_CALL(*mock_factory, unsafeCreateProposal(_, _, _))
@@ -415,3 +426,43 @@ TEST_F(OnDemandOsTest, FailOnCreationStrategy) {
 
   ASSERT_TRUE(os->onRequestProposal(target_round));
 }
+
+/**
+ * @given initialized on-demand OS with proposal size limit of two transactions
+ * @when  five transactions are inserted
+ * @then  proposal contains only two transactions
+ */
+TEST_F(OnDemandOsTest, ProposalBytesLimit) {
+  auto collection = generateTransactions({1, 6});
+  auto const tx_bytes =
+      collection.front()->transactions().front()->blob().size();
+  createOs(tx_bytes * 2 + tx_bytes / 2);
+
+  os->onBatches(std::move(collection));
+  os->onCollaborationOutcome(commit_round);
+
+  auto pack = os->onRequestProposal(target_round);
+  ASSERT_TRUE(pack);
+  ASSERT_EQ(1, pack->size());
+  EXPECT_EQ(2, pack->operator[](0).first->transactions().size());
+}
+
+/**
+ * @given initialized on-demand OS with proposal size limit
+ * @when  batches bigger than the limit on their own are inserted
+ * @then  the proposal contains only one of them
+ */
+TEST_F(OnDemandOsTest, OversizedBatchProposedAlone) {
+  auto collection = generateTransactions({1, 3});
+  auto const tx_bytes =
+      collection.front()->transactions().front()->blob().size();
+  createOs(tx_bytes - 1);
+
+  os->onBatches(std::move(collection));
+  os->onCollaborationOutcome(commit_round);
+
+  auto pack = os->onRequestProposal(target_round);
+  ASSERT_TRUE(pack);
+  ASSERT_EQ(1, pack->size());
+  EXPECT_EQ(1, pack->operator[](0).first->transactions().size());
+}
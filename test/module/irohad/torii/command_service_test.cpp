@@ -5,6 +5,8 @@
 
 #include "torii/impl/command_service_impl.hpp"
 
+#include <optional>
+
 #include <gtest/gtest.h>
 #include "backend/protobuf/proto_tx_status_factory.hpp"
 #include "cryptography/hash.hpp"
@@ -32,14 +34,16 @@ class CommandServiceTest : public Test {
     log_ = getTestLogger("CommandServiceTest");
   }
 
-  void initCommandService() {
+  void initCommandService(
+      std::optional<size_t> max_proposal_bytes = std::nullopt) {
     command_service_ = std::make_shared<iroha::torii::CommandServiceImpl>(
         transaction_processor_,
         status_bus_,
         tx_status_factory_,
         cache_,
         tx_presence_cache_,
-        log_);
+        log_,
+        max_proposal_bytes);
   }
 
   std::shared_ptr<iroha::torii::MockTransactionProcessor>
@@ -75,6 +79,37 @@ TEST_F(CommandServiceTest, ProcessBatchOn) {
   command_service_->handleTransactionBatch(batch);
 }
 
+/**
+ * @given command service with proposal size limit
+ * @when  invoke processBatch on batch bigger than the limit
+ * @then  the transaction gets stateless failed status
+ *        @and tx_processor batchHandle is not invoked
+ */
+TEST_F(CommandServiceTest, OversizedBatchRejected) {
+  auto hash = shared_model::crypto::Hash("a");
+  auto tx = createMockTransactionWithHash(hash);
+  ON_CALL(*tx, blob())
+      .WillByDefault(
+          ReturnRefOfCopy(shared_model::crypto::Blob(std::string(10, 'a'))));
+  auto batch = createMockBatchWithTransactions({tx}, "a");
+
+  EXPECT_CALL(
+      *tx_presence_cache_,
+      check(Matcher<const shared_model::interface::TransactionBatch &>(_)))
+      .Times(0);
+  EXPECT_CALL(*status_bus_, publish(_))
+      .WillOnce(Invoke([&hash](auto const &response) {
+        EXPECT_EQ(hash, response->transactionHash());
+        EXPECT_NO_THROW(boost::get<const shared_model::interface::
+                                       StatelessFailedTxResponse &>(
+            response->get()));
+      }));
+  EXPECT_CALL(*transaction_processor_, batchHandle(_)).Times(0);
+
+  initCommandService(5);
+  command_service_->handleTransactionBatch(batch);
+}
+
 /**
  * @given initialized command service
  * @when  status of a transaction is queried
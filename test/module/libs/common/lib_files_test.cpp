@@ -70,3 +70,28 @@ TEST_F(ReadFileTest, NonexistentFile) {
   auto result = iroha::readBinaryFile(kNonexistentFilePath);
   IROHA_ASSERT_RESULT_ERROR(result);
 }
+
+/**
+ * @given a folder with two files and a nested folder
+ * @when its size is calculated
+ * @then the size is the summary size of the files
+ */
+TEST(DirectorySizeTest, SumsFileSizes) {
+  const fs::path dir{kTestDir / "directory_size"};
+  fs::remove_all(dir);
+  fs::create_directories(dir / "nested");
+  std::ofstream((dir / "text").string()) << kText;
+  std::ofstream((dir / "binary").string(), std::ios::binary) << kBlobString;
+
+  EXPECT_EQ(iroha::directorySize(dir), kText.size() + kBlobString.size());
+}
+
+/**
+ * @given a path which does not exist
+ * @when its size is calculated
+ * @then the size is zero
+ */
+TEST(DirectorySizeTest, NonexistentDirectory) {
+  ASSERT_FALSE(fs::exists(kNonexistentFilePath));
+  EXPECT_EQ(iroha::directorySize(kNonexistentFilePath), 0);
+}
@@ -896,3 +896,45 @@ TEST_F(FieldValidatorTest, QueryContainerFieldsValidation) {
       },
       [] {});
 }
+
+/**
+ * @given field validator with the future gap set in validators config
+ * @when created time is validated
+ * @then timestamps within the configured gap are accepted, and the ones
+ * further in the future are rejected as sent from future
+ */
+TEST(FieldValidatorConfigTest, ConfiguredFutureGap) {
+  auto config = std::make_shared<validation::ValidatorsConfig>(
+      iroha::test::getTestsMaxBatchSize(), false, false, std::nullopt, 60);
+  validation::FieldValidator field_validator(config);
+  auto const now = iroha::time::now();
+
+  EXPECT_EQ(field_validator.validateCreatedTime(
+                now + std::chrono::seconds(30) / std::chrono::milliseconds(1),
+                now),
+            std::nullopt);
+
+  auto error = field_validator.validateCreatedTime(
+      now + std::chrono::seconds(90) / std::chrono::milliseconds(1), now);
+  ASSERT_TRUE(error);
+  EXPECT_THAT(error->toString(), ::testing::HasSubstr("sent from future"));
+}
+
+/**
+ * @given field validator with future gap set both in config and explicitly
+ * @when created time within explicit gap but beyond configured one is
+ * validated
+ * @then the explicit gap takes precedence
+ */
+TEST(FieldValidatorConfigTest, ExplicitFutureGapOverridesConfig) {
+  auto config = std::make_shared<validation::ValidatorsConfig>(
+      iroha::test::getTestsMaxBatchSize(), false, false, std::nullopt, 60);
+  validation::FieldValidator field_validator(
+      config, std::chrono::seconds(120) / std::chrono::milliseconds(1));
+  auto const now = iroha::time::now();
+
+  EXPECT_EQ(field_validator.validateCreatedTime(
+                now + std::chrono::seconds(90) / std::chrono::milliseconds(1),
+                now),
+            std::nullopt);
+}